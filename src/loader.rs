@@ -1,48 +1,123 @@
-use anyhow;
+use std::{io::Read, path::PathBuf};
+
 use bevy::{
-    asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset},
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
     log::debug,
+    render::texture::{Image, ImageType, CompressedImageFormats},
 };
+use flate2::read::GzDecoder;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::svg::Svg;
 
+/// Magic number identifying a gzip byte stream, per RFC 1952.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
 #[derive(Default)]
 pub struct SvgAssetLoader;
 
+/// Tessellation quality settings for [`SvgAssetLoader`], selectable per-asset
+/// through a `.svg.meta` file.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SvgSettings {
+    /// Maximum distance, in SVG units, between a curve and its tessellated
+    /// approximation. Lower values produce smoother curves at the cost of
+    /// more triangles.
+    pub tolerance: f32,
+}
+
+impl SvgSettings {
+    /// A tight tolerance suited for small, detail-sensitive SVGs like logos.
+    pub const HIGH_QUALITY: Self = Self { tolerance: 0.01 };
+    /// A loose tolerance suited for large background SVGs, where per-pixel
+    /// curve accuracy doesn't matter.
+    pub const LOW_QUALITY: Self = Self { tolerance: 1.0 };
+}
+
+impl Default for SvgSettings {
+    fn default() -> Self {
+        Self::HIGH_QUALITY
+    }
+}
+
 impl AssetLoader for SvgAssetLoader {
-    fn load<'a>(
+    type Asset = Svg;
+    type Settings = SvgSettings;
+    type Error = SvgError;
+
+    async fn load<'a>(
         &'a self,
-        bytes: &'a [u8],
-        load_context: &'a mut LoadContext,
-    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
-        Box::pin(async move {
-            debug!("Parsing SVG: {} ...", load_context.path().display());
-            let mut svg = Svg::from_bytes(bytes, load_context.path(), None::<&std::path::Path>)?;
-            let name = &load_context
-                .path()
-                .file_name()
-                .ok_or_else(|| FileSvgError {
-                    error: SvgError::InvalidFileName(load_context.path().display().to_string()),
-                    path: format!("{}", load_context.path().display()),
-                })?
-                .to_string_lossy();
-            svg.name = name.to_string();
-            debug!("Parsing SVG: {} ... Done", load_context.path().display());
-
-            debug!("Tessellating SVG: {} ...", load_context.path().display());
-            let mesh = svg.tessellate();
-            debug!(
-                "Tessellating SVG: {} ... Done",
-                load_context.path().display()
-            );
-            let mesh_handle = load_context.set_labeled_asset("mesh", LoadedAsset::new(mesh));
-            svg.mesh = mesh_handle;
-
-            load_context.set_default_asset(LoadedAsset::new(svg));
-
-            Ok(())
-        })
+        reader: &'a mut Reader<'_>,
+        settings: &'a SvgSettings,
+        load_context: &'a mut LoadContext<'_>,
+    ) -> Result<Svg, SvgError> {
+        let path = load_context.path().to_path_buf();
+
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|source| SvgError::Io {
+                path: path.clone(),
+                source,
+            })?;
+
+        let bytes = if bytes.starts_with(&GZIP_MAGIC) {
+            debug!("Decompressing svgz: {} ...", path.display());
+            let mut decompressed = Vec::new();
+            GzDecoder::new(bytes.as_slice())
+                .read_to_end(&mut decompressed)
+                .map_err(|source| SvgError::Decompression {
+                    path: path.clone(),
+                    source,
+                })?;
+            debug!("Decompressing svgz: {} ... Done", path.display());
+            decompressed
+        } else {
+            bytes
+        };
+
+        debug!("Parsing SVG: {} ...", path.display());
+        let mut svg =
+            Svg::from_bytes(&bytes, &path, None::<&std::path::Path>).map_err(|source| {
+                SvgError::Svg {
+                    path: path.clone(),
+                    source,
+                }
+            })?;
+        let name = path
+            .file_name()
+            .ok_or_else(|| SvgError::InvalidFileName(path.clone()))?
+            .to_string_lossy();
+        svg.name = name.to_string();
+        debug!("Parsing SVG: {} ... Done", path.display());
+
+        debug!("Tessellating SVG: {} ...", path.display());
+        let tessellated = svg.tessellate(settings.tolerance);
+        debug!("Tessellating SVG: {} ... Done", path.display());
+        svg.mesh = load_context.add_labeled_asset("mesh".to_string(), tessellated.mesh);
+
+        for (index, (raster, mesh)) in tessellated.textured_meshes.into_iter().enumerate() {
+            let extension = sniff_image_extension(&raster).ok_or_else(|| SvgError::UnknownImageFormat {
+                path: path.clone(),
+            })?;
+            let image = Image::from_buffer(
+                &raster,
+                ImageType::Extension(extension),
+                CompressedImageFormats::NONE,
+                true,
+            )
+            .map_err(|source| SvgError::Texture {
+                path: path.clone(),
+                source,
+            })?;
+            let texture = load_context.add_labeled_asset(format!("texture{index}"), image);
+            let mesh = load_context.add_labeled_asset(format!("mesh_texture{index}"), mesh);
+            svg.textured_meshes.push((texture, mesh));
+        }
+
+        Ok(svg)
     }
 
     fn extensions(&self) -> &[&str] {
@@ -50,27 +125,89 @@ impl AssetLoader for SvgAssetLoader {
     }
 }
 
-/// An error that occurs when loading a texture
-#[derive(Error, Debug)]
-pub enum SvgError {
-    #[error("invalid file name")]
-    InvalidFileName(String),
-    #[error("failed to load an SVG: {0}")]
-    SvgError(#[from] usvg::Error),
+/// Identifies a raster image's format by its magic number, independent of
+/// any (possibly missing or wrong) file extension.
+fn sniff_image_extension(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+        Some("png")
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("jpg")
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some("gif")
+    } else if bytes.starts_with(b"BM") {
+        Some("bmp")
+    } else if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        Some("webp")
+    } else {
+        None
+    }
 }
 
-/// An error that occurs when loading a texture from a file.
+/// An error that occurs when loading an [`Svg`] asset, naming the offending
+/// file so `bevy_svg`'s own error messages don't need a separate wrapper.
 #[derive(Error, Debug)]
-pub struct FileSvgError {
-    pub(crate) error: SvgError,
-    pub(crate) path: String,
+pub enum SvgError {
+    #[error("{} has an invalid file name", .0.display())]
+    InvalidFileName(PathBuf),
+    #[error("failed to parse SVG {path}: {source}")]
+    Svg { path: PathBuf, source: usvg::Error },
+    #[error("embedded image in {path} has an unrecognized format")]
+    UnknownImageFormat { path: PathBuf },
+    #[error("failed to load an embedded image from {path}: {source}")]
+    Texture {
+        path: PathBuf,
+        source: bevy::render::texture::TextureError,
+    },
+    #[error("failed to decompress gzip-compressed SVG {path}: {source}")]
+    Decompression {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
 }
-impl std::fmt::Display for FileSvgError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
-        write!(
-            f,
-            "Error reading SVG file {}: {}, this is an error in `bevy_svg`.",
-            self.path, self.error
-        )
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sniffs_png() {
+        assert_eq!(
+            sniff_image_extension(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0]),
+            Some("png")
+        );
+    }
+
+    #[test]
+    fn sniffs_jpg() {
+        assert_eq!(sniff_image_extension(&[0xFF, 0xD8, 0xFF, 0xE0]), Some("jpg"));
+    }
+
+    #[test]
+    fn sniffs_gif() {
+        assert_eq!(sniff_image_extension(b"GIF89a...."), Some("gif"));
+    }
+
+    #[test]
+    fn sniffs_bmp() {
+        assert_eq!(sniff_image_extension(b"BM......"), Some("bmp"));
+    }
+
+    #[test]
+    fn sniffs_webp() {
+        assert_eq!(
+            sniff_image_extension(b"RIFF\0\0\0\0WEBP"),
+            Some("webp")
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_format() {
+        assert_eq!(sniff_image_extension(b"not an image"), None);
+        assert_eq!(sniff_image_extension(&[]), None);
     }
 }
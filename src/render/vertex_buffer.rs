@@ -1,5 +1,5 @@
 use bevy::{
-    math::Vec3,
+    math::{Vec2, Vec3},
     render::{
         color::Color, mesh::{Indices, Mesh},
         render_resource::PrimitiveTopology,
@@ -17,6 +17,7 @@ use crate::Convert;
 pub(crate) struct Vertex {
     position: [f32; 3],
     color: [f32; 4],
+    uv: [f32; 2],
 }
 
 /// The index type of a Bevy [`Mesh`](bevy::render::mesh::Mesh).
@@ -29,10 +30,12 @@ impl Convert<Mesh> for VertexBuffers {
     fn convert(self) -> Mesh {
         let mut positions = Vec::with_capacity(self.vertices.len());
         let mut colors = Vec::with_capacity(self.vertices.len());
+        let mut uvs = Vec::with_capacity(self.vertices.len());
 
         self.vertices.iter().for_each(|v| {
             positions.push(v.position);
             colors.push(v.color);
+            uvs.push(v.uv);
         });
 
         let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
@@ -45,21 +48,159 @@ impl Convert<Mesh> for VertexBuffers {
             Mesh::ATTRIBUTE_COLOR,
             colors
         );
+        mesh.set_attribute(
+            Mesh::ATTRIBUTE_UV_0,
+            uvs
+        );
 
         mesh
     }
 }
 
+/// A single color stop in a gradient, as found on usvg's `Stop`.
+///
+/// `offset` is normalized to the `0.0..=1.0` range along the gradient.
+pub(crate) type GradientStop = (f32, Color);
+
+/// The fill/stroke paint of a path, carrying enough information to compute a
+/// per-vertex color from the vertex's pre-transform SVG-space position.
+///
+/// Gradients are kept in SVG-space (pre-transform) coordinates because
+/// tessellation happens before the shape is placed in world space; the
+/// mesh's vertex colors are then interpolated by Bevy across each triangle,
+/// so no further work is needed at render time.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Paint {
+    Solid(Color),
+    Linear {
+        start: Vec2,
+        end: Vec2,
+        stops: Vec<GradientStop>,
+        /// The gradient's `gradientTransform`, kept separate from `start`/`end`
+        /// because usvg does, and applied to the sampled position the same
+        /// way `VertexConstructor` applies a path's own transform.
+        transform: Transform,
+    },
+    Radial {
+        center: Vec2,
+        radius: f32,
+        /// The gradient's focal point (`fx`/`fy`); equal to `center` unless
+        /// the SVG sets it separately.
+        focal: Vec2,
+        stops: Vec<GradientStop>,
+        /// The gradient's `gradientTransform`, see [`Paint::Linear::transform`].
+        transform: Transform,
+    },
+}
+
+impl Paint {
+    /// Computes the color at SVG-space position `p`, sampling the gradient
+    /// stops if this paint isn't a flat [`Paint::Solid`].
+    fn color_at(&self, p: Vec2) -> Color {
+        match self {
+            Paint::Solid(color) => *color,
+            Paint::Linear { start, end, stops, transform } => {
+                let p = apply_transform(transform, p);
+                let axis = *end - *start;
+                let len_sq = axis.length_squared();
+                let t = if len_sq > 0.0 {
+                    ((p - *start).dot(axis) / len_sq).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                sample_stops(stops, t)
+            }
+            Paint::Radial { center: _, radius, focal, stops, transform } => {
+                let p = apply_transform(transform, p);
+                let t = if *radius > 0.0 {
+                    ((p - *focal).length() / radius).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                sample_stops(stops, t)
+            }
+        }
+    }
+}
+
+/// Applies a gradient's `gradientTransform` to a pre-transform SVG-space
+/// position, the same way [`VertexConstructor`] applies a path's own
+/// transform to the tessellated vertex position.
+fn apply_transform(transform: &Transform, p: Vec2) -> Vec2 {
+    let p = *transform * Vec3::new(p.x, p.y, 0.0);
+    Vec2::new(p.x, p.y)
+}
+
+/// Finds the stop pair bounding `t` and linearly interpolates their colors
+/// in linear RGBA space.
+fn sample_stops(stops: &[GradientStop], t: f32) -> Color {
+    if stops.is_empty() {
+        return Color::NONE;
+    }
+
+    if t <= stops[0].0 {
+        return stops[0].1;
+    }
+    if let Some(last) = stops.last() {
+        if t >= last.0 {
+            return last.1;
+        }
+    }
+
+    for window in stops.windows(2) {
+        let (offset_a, color_a) = window[0];
+        let (offset_b, color_b) = window[1];
+        if t >= offset_a && t <= offset_b {
+            let span = offset_b - offset_a;
+            let local_t = if span > 0.0 { (t - offset_a) / span } else { 0.0 };
+            let a = color_a.as_linear_rgba_f32();
+            let b = color_b.as_linear_rgba_f32();
+            return Color::rgba_linear(
+                a[0] + (b[0] - a[0]) * local_t,
+                a[1] + (b[1] - a[1]) * local_t,
+                a[2] + (b[2] - a[2]) * local_t,
+                a[3] + (b[3] - a[3]) * local_t,
+            );
+        }
+    }
+
+    stops.last().unwrap().1
+}
+
+/// The SVG-space bounding box of the path/shape currently being tessellated,
+/// used to derive normalized UV coordinates for pattern and image fills.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Bounds {
+    pub(crate) min: Vec2,
+    pub(crate) max: Vec2,
+}
+
+impl Bounds {
+    /// Normalizes `p`, a pre-transform SVG-space position, to `0.0..=1.0`
+    /// local coordinates within this bounding box.
+    fn normalize(&self, p: Vec2) -> Vec2 {
+        let size = self.max - self.min;
+        Vec2::new(
+            if size.x > 0.0 { (p.x - self.min.x) / size.x } else { 0.0 },
+            if size.y > 0.0 { (p.y - self.min.y) / size.y } else { 0.0 },
+        )
+    }
+}
+
 /// Zero-sized type used to implement various vertex construction traits from Lyon.
 pub(crate) struct VertexConstructor {
-    pub(crate) color: Color,
+    pub(crate) paint: Paint,
     pub(crate) transform: Transform,
+    pub(crate) bounds: Bounds,
 }
 
 /// Enables the construction of a [`Vertex`] when using a `FillTessellator`.
 impl FillVertexConstructor<Vertex> for VertexConstructor {
     fn new_vertex(&mut self, vertex: FillVertex) -> Vertex {
         let vertex = vertex.position();
+        let svg_pos = Vec2::new(vertex.x, vertex.y);
+        let color = self.paint.color_at(svg_pos);
+        let uv = self.bounds.normalize(svg_pos);
         let pos = self.transform * Vec3::new(
             vertex.x,
             vertex.y,
@@ -68,7 +209,8 @@ impl FillVertexConstructor<Vertex> for VertexConstructor {
 
         Vertex {
             position: [pos.x, pos.y, pos.z],
-            color: self.color.as_linear_rgba_f32(),
+            color: color.as_linear_rgba_f32(),
+            uv: [uv.x, uv.y],
         }
     }
 }
@@ -77,6 +219,9 @@ impl FillVertexConstructor<Vertex> for VertexConstructor {
 impl StrokeVertexConstructor<Vertex> for VertexConstructor {
     fn new_vertex(&mut self, vertex: StrokeVertex) -> Vertex {
         let vertex = vertex.position();
+        let svg_pos = Vec2::new(vertex.x, vertex.y);
+        let color = self.paint.color_at(svg_pos);
+        let uv = self.bounds.normalize(svg_pos);
         let pos = self.transform * Vec3::new(
             vertex.x,
             vertex.y,
@@ -85,7 +230,8 @@ impl StrokeVertexConstructor<Vertex> for VertexConstructor {
 
         Vertex {
             position: [pos.x, pos.y, pos.z],
-            color: self.color.as_linear_rgba_f32(),
+            color: color.as_linear_rgba_f32(),
+            uv: [uv.x, uv.y],
         }
     }
 }
@@ -114,4 +260,84 @@ impl BufferExt<VertexBuffers> for VertexBuffers {
             offset += buf.vertices.len() as u32;
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_stops_before_first_offset_clamps_to_first_color() {
+        let stops = vec![(0.25, Color::RED), (0.75, Color::BLUE)];
+        assert_eq!(sample_stops(&stops, 0.0), Color::RED);
+    }
+
+    #[test]
+    fn sample_stops_after_last_offset_clamps_to_last_color() {
+        let stops = vec![(0.25, Color::RED), (0.75, Color::BLUE)];
+        assert_eq!(sample_stops(&stops, 1.0), Color::BLUE);
+    }
+
+    #[test]
+    fn sample_stops_between_offsets_lerps() {
+        let stops = vec![(0.0, Color::BLACK), (1.0, Color::WHITE)];
+        let mid = sample_stops(&stops, 0.5).as_linear_rgba_f32();
+        assert!((mid[0] - 0.5).abs() < 1e-5);
+        assert!((mid[1] - 0.5).abs() < 1e-5);
+        assert!((mid[2] - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn sample_stops_empty_returns_none_color() {
+        assert_eq!(sample_stops(&[], 0.5), Color::NONE);
+    }
+
+    #[test]
+    fn linear_paint_degenerate_axis_does_not_panic() {
+        let paint = Paint::Linear {
+            start: Vec2::new(1.0, 1.0),
+            end: Vec2::new(1.0, 1.0),
+            stops: vec![(0.0, Color::RED), (1.0, Color::BLUE)],
+            transform: Transform::IDENTITY,
+        };
+        assert_eq!(paint.color_at(Vec2::new(5.0, 5.0)), Color::RED);
+    }
+
+    #[test]
+    fn radial_paint_zero_radius_does_not_panic() {
+        let paint = Paint::Radial {
+            center: Vec2::ZERO,
+            radius: 0.0,
+            focal: Vec2::ZERO,
+            stops: vec![(0.0, Color::RED), (1.0, Color::BLUE)],
+            transform: Transform::IDENTITY,
+        };
+        assert_eq!(paint.color_at(Vec2::new(5.0, 5.0)), Color::RED);
+    }
+
+    #[test]
+    fn solid_paint_ignores_position() {
+        let paint = Paint::Solid(Color::GREEN);
+        assert_eq!(paint.color_at(Vec2::new(100.0, -100.0)), Color::GREEN);
+    }
+
+    #[test]
+    fn bounds_normalize_maps_corners_to_unit_square() {
+        let bounds = Bounds {
+            min: Vec2::new(10.0, 20.0),
+            max: Vec2::new(30.0, 60.0),
+        };
+        assert_eq!(bounds.normalize(Vec2::new(10.0, 20.0)), Vec2::ZERO);
+        assert_eq!(bounds.normalize(Vec2::new(30.0, 60.0)), Vec2::ONE);
+        assert_eq!(bounds.normalize(Vec2::new(20.0, 40.0)), Vec2::new(0.5, 0.5));
+    }
+
+    #[test]
+    fn bounds_normalize_degenerate_extent_does_not_divide_by_zero() {
+        let bounds = Bounds {
+            min: Vec2::new(5.0, 5.0),
+            max: Vec2::new(5.0, 5.0),
+        };
+        assert_eq!(bounds.normalize(Vec2::new(5.0, 5.0)), Vec2::ZERO);
+    }
+}